@@ -16,6 +16,9 @@ fn get_api_version(libusb_source: &Path) {
                             None
                         }
                     }) {
+                        if api_version >= 0x01000105 {
+                            println!("cargo:rustc-cfg=libusb_interrupt_event_handler");
+                        }
                         if api_version >= 0x01000108 {
                             println!("cargo:rustc-cfg=libusb_hotplug_get_user_data");
                         }
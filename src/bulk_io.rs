@@ -0,0 +1,197 @@
+use std::{io, time::Duration};
+
+use libusb1_sys::constants::*;
+
+use crate::{device_handle::DeviceHandle, error::Error, UsbContext};
+
+/// Reads from a bulk IN endpoint through `std::io::Read`.
+///
+/// Each call to `read` performs one bulk transfer of up to `buf.len()` bytes. The call blocks
+/// for at most the reader's timeout. Errors are converted with
+/// [`From<Error> for io::Error`](enum.Error.html), so a timeout is reported as
+/// `io::ErrorKind::TimedOut`.
+///
+/// A zero-length packet from the device makes `read` return `Ok(0)`. `Read` treats that as
+/// end of file, so helpers such as `read_to_end` stop there.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::io::Read;
+/// use std::time::Duration;
+///
+/// let handle = rusb::open_device_with_vid_pid(0x1234, 0x5678).unwrap();
+/// let mut reader = rusb::BulkReader::new(&handle, 0x81, Duration::from_secs(1)).unwrap();
+///
+/// let mut buf = [0u8; 512];
+/// let len = reader.read(&mut buf).unwrap();
+/// ```
+pub struct BulkReader<'a, T: UsbContext> {
+    handle: &'a DeviceHandle<T>,
+    endpoint: u8,
+    timeout: Duration,
+}
+
+impl<'a, T: UsbContext> BulkReader<'a, T> {
+    /// Creates a reader for the bulk endpoint with the address given by `endpoint`.
+    ///
+    /// Returns `InvalidParam` if `endpoint` is not an input endpoint.
+    pub fn new(
+        handle: &'a DeviceHandle<T>,
+        endpoint: u8,
+        timeout: Duration,
+    ) -> crate::Result<Self> {
+        if endpoint & LIBUSB_ENDPOINT_DIR_MASK != LIBUSB_ENDPOINT_IN {
+            return Err(Error::InvalidParam);
+        }
+
+        Ok(BulkReader {
+            handle,
+            endpoint,
+            timeout,
+        })
+    }
+
+    /// Returns the device handle the reader reads from.
+    pub fn handle(&self) -> &'a DeviceHandle<T> {
+        self.handle
+    }
+
+    /// Returns the address of the endpoint the reader reads from.
+    pub fn endpoint(&self) -> u8 {
+        self.endpoint
+    }
+
+    /// Returns the timeout applied to each transfer.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Sets the timeout applied to each transfer.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Reads a response that the device terminates with a short packet.
+    ///
+    /// Reads of `max_packet_size` bytes, normally the endpoint's `wMaxPacketSize`, are
+    /// repeated until one returns fewer bytes, including a zero-length packet. All data read
+    /// is returned.
+    ///
+    /// ## Errors
+    ///
+    /// * `InvalidParam` if `max_packet_size` is zero.
+    /// * `Timeout` if a read times out before receiving any data.
+    /// * Any error returned by [`DeviceHandle::read_bulk`](struct.DeviceHandle.html#method.read_bulk).
+    ///   Data received before the error is discarded.
+    pub fn read_until_short(&mut self, max_packet_size: usize) -> crate::Result<Vec<u8>> {
+        if max_packet_size == 0 {
+            return Err(Error::InvalidParam);
+        }
+
+        let mut data = Vec::new();
+        loop {
+            let start = data.len();
+            data.resize(start + max_packet_size, 0);
+            let len = self
+                .handle
+                .read_bulk(self.endpoint, &mut data[start..], self.timeout)?;
+            data.truncate(start + len);
+
+            if len < max_packet_size {
+                return Ok(data);
+            }
+        }
+    }
+}
+
+impl<'a, T: UsbContext> io::Read for BulkReader<'a, T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        Ok(self.handle.read_bulk(self.endpoint, buf, self.timeout)?)
+    }
+}
+
+/// Writes to a bulk OUT endpoint through `std::io::Write`.
+///
+/// Each call to `write` performs one bulk transfer of `buf`. The call blocks for at most the
+/// writer's timeout. If the transfer times out after part of `buf` has gone to the device,
+/// `write` returns that byte count. Use `write_all` to keep writing the rest. Errors are
+/// converted with [`From<Error> for io::Error`](enum.Error.html).
+///
+/// Data is never buffered, so `flush` does nothing.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::io::Write;
+/// use std::time::Duration;
+///
+/// let handle = rusb::open_device_with_vid_pid(0x1234, 0x5678).unwrap();
+/// let mut writer = rusb::BulkWriter::new(&handle, 0x02, Duration::from_secs(1)).unwrap();
+///
+/// writer.write_all(b"hello").unwrap();
+/// ```
+pub struct BulkWriter<'a, T: UsbContext> {
+    handle: &'a DeviceHandle<T>,
+    endpoint: u8,
+    timeout: Duration,
+}
+
+impl<'a, T: UsbContext> BulkWriter<'a, T> {
+    /// Creates a writer for the bulk endpoint with the address given by `endpoint`.
+    ///
+    /// Returns `InvalidParam` if `endpoint` is not an output endpoint.
+    pub fn new(
+        handle: &'a DeviceHandle<T>,
+        endpoint: u8,
+        timeout: Duration,
+    ) -> crate::Result<Self> {
+        if endpoint & LIBUSB_ENDPOINT_DIR_MASK != LIBUSB_ENDPOINT_OUT {
+            return Err(Error::InvalidParam);
+        }
+
+        Ok(BulkWriter {
+            handle,
+            endpoint,
+            timeout,
+        })
+    }
+
+    /// Returns the device handle the writer writes to.
+    pub fn handle(&self) -> &'a DeviceHandle<T> {
+        self.handle
+    }
+
+    /// Returns the address of the endpoint the writer writes to.
+    pub fn endpoint(&self) -> u8 {
+        self.endpoint
+    }
+
+    /// Returns the timeout applied to each transfer.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Sets the timeout applied to each transfer.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+}
+
+impl<'a, T: UsbContext> io::Write for BulkWriter<'a, T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        Ok(self.handle.write_bulk(self.endpoint, buf, self.timeout)?)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
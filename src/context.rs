@@ -15,6 +15,12 @@ type Seconds = ::libc::time_t;
 #[cfg(not(windows))]
 type MicroSeconds = ::libc::suseconds_t;
 
+// Not bound by libusb1-sys yet. Added in libusb 1.0.21 (API version 0x01000105).
+#[cfg(libusb_interrupt_event_handler)]
+extern "system" {
+    fn libusb_interrupt_event_handler(ctx: *mut libusb_context);
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Default)]
 pub struct GlobalContext {}
 
@@ -166,6 +172,19 @@ pub trait UsbContext: Clone + Sized + Send + Sync {
             Ok(())
         }
     }
+
+    /// Interrupts a thread that is blocked in [`handle_events`](#method.handle_events).
+    ///
+    /// This can be called from any thread, for example to shut down a thread that services
+    /// events for this context. The interrupted `handle_events` call returns `Ok(())` as if an
+    /// event had been handled. A thread that handles events in a loop should therefore re-check
+    /// its own shutdown condition each time `handle_events` returns.
+    ///
+    /// This method is only available when rusb is built against libusb 1.0.21 or newer.
+    #[cfg(libusb_interrupt_event_handler)]
+    fn interrupt_event_handler(&self) {
+        unsafe { libusb_interrupt_event_handler(self.as_raw()) }
+    }
 }
 
 impl UsbContext for Context {
@@ -0,0 +1,187 @@
+use std::time::Duration;
+
+use crate::{device_handle::DeviceHandle, error::Error, UsbContext};
+
+/// Where the byte offset of each chunk is placed in a control transfer setup packet.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum OffsetEncoding {
+    /// The offset replaces `wValue`. `wIndex` is sent unchanged.
+    Value,
+
+    /// The offset replaces `wIndex`. `wValue` is sent unchanged.
+    Index,
+
+    /// The low 16 bits of the offset replace `wValue` and the high 16 bits replace `wIndex`.
+    ValueAndIndex,
+}
+
+impl OffsetEncoding {
+    /// Returns the `(wValue, wIndex)` pair used to request the chunk at `offset`.
+    fn encode(self, offset: usize, value: u16, index: u16) -> crate::Result<(u16, u16)> {
+        match self {
+            OffsetEncoding::Value if offset <= u16::MAX as usize => Ok((offset as u16, index)),
+            OffsetEncoding::Index if offset <= u16::MAX as usize => Ok((value, offset as u16)),
+            OffsetEncoding::ValueAndIndex if offset <= u32::MAX as usize => {
+                Ok((offset as u16, (offset >> 16) as u16))
+            }
+            _ => Err(Error::Overflow),
+        }
+    }
+}
+
+/// Reads a payload larger than a single control transfer by paging through it.
+///
+/// Some vendor protocols expose blobs that do not fit in one control transfer. The host reads
+/// them in chunks and encodes the byte offset of each chunk in the setup packet. A
+/// `ControlReader` issues those reads one after another and accumulates the data. It stops at
+/// the first short chunk, which the device sends to mark the end of the payload.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use rusb::{ControlReader, Direction, OffsetEncoding, Recipient, RequestType};
+///
+/// let handle = rusb::open_device_with_vid_pid(0x1234, 0x5678).unwrap();
+/// let request_type = rusb::request_type(Direction::In, RequestType::Vendor, Recipient::Device);
+///
+/// let blob = ControlReader::new(&handle, request_type, 0x42, 64, Duration::from_secs(1))
+///     .offset_encoding(OffsetEncoding::Index)
+///     .read_to_end()
+///     .unwrap();
+/// ```
+pub struct ControlReader<'a, T: UsbContext> {
+    handle: &'a DeviceHandle<T>,
+    request_type: u8,
+    request: u8,
+    value: u16,
+    index: u16,
+    encoding: OffsetEncoding,
+    chunk_size: u16,
+    max_length: Option<usize>,
+    timeout: Duration,
+}
+
+impl<'a, T: UsbContext> ControlReader<'a, T> {
+    /// Creates a reader that requests `chunk_size` bytes at a time.
+    ///
+    /// `request_type` and `request` are sent unchanged with every chunk. By default, `wValue`
+    /// carries the chunk's offset and `wIndex` is zero. Each chunk may take up to `timeout`.
+    pub fn new(
+        handle: &'a DeviceHandle<T>,
+        request_type: u8,
+        request: u8,
+        chunk_size: u16,
+        timeout: Duration,
+    ) -> Self {
+        ControlReader {
+            handle,
+            request_type,
+            request,
+            value: 0,
+            index: 0,
+            encoding: OffsetEncoding::Value,
+            chunk_size,
+            max_length: None,
+            timeout,
+        }
+    }
+
+    /// Sets the `wValue` sent when the offset is not encoded in it.
+    pub fn value(mut self, value: u16) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the `wIndex` sent when the offset is not encoded in it.
+    pub fn index(mut self, index: u16) -> Self {
+        self.index = index;
+        self
+    }
+
+    /// Sets how each chunk's byte offset is encoded in the setup packet.
+    pub fn offset_encoding(mut self, encoding: OffsetEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Stops reading after `max_length` bytes even if the device has not sent a short chunk.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Reads chunks until the device returns a short one and returns all data received.
+    ///
+    /// ## Errors
+    ///
+    /// * `InvalidParam` if the chunk size is zero or `request_type` does not specify a read.
+    /// * `Overflow` if the next offset cannot be represented by the offset encoding.
+    /// * Any error returned by [`DeviceHandle::read_control`](struct.DeviceHandle.html#method.read_control).
+    pub fn read_to_end(&self) -> crate::Result<Vec<u8>> {
+        if self.chunk_size == 0 {
+            return Err(Error::InvalidParam);
+        }
+
+        let mut data = Vec::new();
+        loop {
+            let start = data.len();
+            let chunk_size = match self.max_length {
+                Some(max_length) if max_length <= start => return Ok(data),
+                Some(max_length) => (max_length - start).min(self.chunk_size as usize),
+                None => self.chunk_size as usize,
+            };
+            let (value, index) = self.encoding.encode(start, self.value, self.index)?;
+
+            data.resize(start + chunk_size, 0);
+            let len = self.handle.read_control(
+                self.request_type,
+                self.request,
+                value,
+                index,
+                &mut data[start..],
+                self.timeout,
+            )?;
+            data.truncate(start + len);
+
+            if len < chunk_size {
+                return Ok(data);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn value_encoding_replaces_value() {
+        assert_eq!(Ok((0x1234, 7)), OffsetEncoding::Value.encode(0x1234, 3, 7));
+    }
+
+    #[test]
+    fn index_encoding_replaces_index() {
+        assert_eq!(Ok((3, 0x1234)), OffsetEncoding::Index.encode(0x1234, 3, 7));
+    }
+
+    #[test]
+    fn value_and_index_encoding_splits_offset() {
+        assert_eq!(
+            Ok((0x5678, 0x1234)),
+            OffsetEncoding::ValueAndIndex.encode(0x1234_5678, 3, 7)
+        );
+    }
+
+    #[test]
+    fn single_field_encodings_reject_offsets_past_u16() {
+        assert_eq!(
+            Err(Error::Overflow),
+            OffsetEncoding::Value.encode(0x1_0000, 3, 7)
+        );
+        assert_eq!(
+            Err(Error::Overflow),
+            OffsetEncoding::Index.encode(0x1_0000, 3, 7)
+        );
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
     config_descriptor::{self, ConfigDescriptor},
     device_descriptor::{self, DeviceDescriptor},
     device_handle::DeviceHandle,
-    fields::{self, Speed},
+    fields::{self, Speed, TransferType},
     Error, UsbContext,
     error,
 };
@@ -124,6 +124,46 @@ impl<T: UsbContext> Device<T> {
         fields::speed_from_libusb(unsafe { libusb_get_device_speed(self.device.as_ptr()) })
     }
 
+    /// Returns the maximum number of bytes an isochronous endpoint can transfer in a single
+    /// packet.
+    ///
+    /// Unlike the endpoint descriptor's `wMaxPacketSize`, this takes the high-bandwidth
+    /// multiplier into account, so it is the value to use when sizing isochronous packets.
+    ///
+    /// ## Errors
+    ///
+    /// * `NotFound` if the endpoint does not exist in the active configuration.
+    /// * `InvalidParam` if the endpoint is not an isochronous endpoint.
+    pub fn max_iso_packet_size(&self, endpoint: u8) -> crate::Result<usize> {
+        let config = self.active_config_descriptor()?;
+
+        let mut found = false;
+        let mut isochronous = false;
+        for interface in config.interfaces() {
+            for descriptor in interface.descriptors() {
+                for endpoint_descriptor in descriptor.endpoint_descriptors() {
+                    if endpoint_descriptor.address() == endpoint {
+                        found = true;
+                        isochronous |=
+                            endpoint_descriptor.transfer_type() == TransferType::Isochronous;
+                    }
+                }
+            }
+        }
+
+        if !found {
+            return Err(Error::NotFound);
+        }
+        if !isochronous {
+            return Err(Error::InvalidParam);
+        }
+
+        match unsafe { libusb_get_max_iso_packet_size(self.device.as_ptr(), endpoint) } {
+            n if n < 0 => Err(error::from_libusb(n)),
+            n => Ok(n as usize),
+        }
+    }
+
     /// Opens the device.
     pub fn open(&self) -> crate::Result<DeviceHandle<T>> {
         let mut handle = mem::MaybeUninit::<*mut libusb_device_handle>::uninit();
@@ -704,6 +704,33 @@ impl<T: UsbContext> DeviceHandle<T> {
         String::from_utf16(&utf16).map_err(|_| Error::Other)
     }
 
+    /// Reads a string descriptor from the device in the first language it supports.
+    ///
+    /// This reads the device's languages with [`read_languages`](#method.read_languages) and
+    /// then reads the string with [`read_string_descriptor`](#method.read_string_descriptor).
+    /// `timeout` applies to each of the two control transfers.
+    ///
+    /// ## Errors
+    ///
+    /// * `InvalidParam` if `index` is zero, which is the index of the language list itself.
+    /// * `NotFound` if the device does not report any languages.
+    pub fn read_string_descriptor_default_language(
+        &self,
+        index: u8,
+        timeout: Duration,
+    ) -> crate::Result<String> {
+        if index == 0 {
+            return Err(Error::InvalidParam);
+        }
+
+        let language = match self.read_languages(timeout)?.first() {
+            Some(&language) => language,
+            None => return Err(Error::NotFound),
+        };
+
+        self.read_string_descriptor(language, index, timeout)
+    }
+
     /// Reads the device's manufacturer string descriptor (ascii).
     pub fn read_manufacturer_string_ascii(
         &self,
@@ -1,4 +1,4 @@
-use std::{fmt, result};
+use std::{fmt, io, result};
 
 use libusb1_sys::constants::*;
 
@@ -74,6 +74,31 @@ impl fmt::Display for Error {
 
 impl std::error::Error for Error {}
 
+impl From<Error> for io::Error {
+    /// Converts a `libusb` error into the closest matching I/O error.
+    ///
+    /// The original `Error` is kept as the I/O error's inner error.
+    fn from(err: Error) -> io::Error {
+        let kind = match err {
+            Error::InvalidParam => io::ErrorKind::InvalidInput,
+            Error::Access => io::ErrorKind::PermissionDenied,
+            Error::NoDevice => io::ErrorKind::NotConnected,
+            Error::NotFound => io::ErrorKind::NotFound,
+            Error::Timeout => io::ErrorKind::TimedOut,
+            Error::Interrupted => io::ErrorKind::Interrupted,
+            Error::Overflow | Error::BadDescriptor => io::ErrorKind::InvalidData,
+            Error::Io
+            | Error::Busy
+            | Error::Pipe
+            | Error::NoMem
+            | Error::NotSupported
+            | Error::Other => io::ErrorKind::Other,
+        };
+
+        io::Error::new(kind, err)
+    }
+}
+
 #[doc(hidden)]
 pub(crate) fn from_libusb(err: i32) -> Error {
     match err {
@@ -102,3 +127,39 @@ macro_rules! try_unsafe {
         }
     };
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn io_error_kind_matches_error() {
+        let expected = [
+            (Error::Io, io::ErrorKind::Other),
+            (Error::InvalidParam, io::ErrorKind::InvalidInput),
+            (Error::Access, io::ErrorKind::PermissionDenied),
+            (Error::NoDevice, io::ErrorKind::NotConnected),
+            (Error::NotFound, io::ErrorKind::NotFound),
+            (Error::Busy, io::ErrorKind::Other),
+            (Error::Timeout, io::ErrorKind::TimedOut),
+            (Error::Overflow, io::ErrorKind::InvalidData),
+            (Error::Pipe, io::ErrorKind::Other),
+            (Error::Interrupted, io::ErrorKind::Interrupted),
+            (Error::NoMem, io::ErrorKind::Other),
+            (Error::NotSupported, io::ErrorKind::Other),
+            (Error::BadDescriptor, io::ErrorKind::InvalidData),
+            (Error::Other, io::ErrorKind::Other),
+        ];
+
+        for &(err, kind) in expected.iter() {
+            assert_eq!(kind, io::Error::from(err).kind(), "kind for {:?}", err);
+        }
+    }
+
+    #[test]
+    fn io_error_keeps_original_error() {
+        let io_err = io::Error::from(Error::Pipe);
+        let inner = io_err.get_ref().and_then(|e| e.downcast_ref::<Error>());
+        assert_eq!(Some(&Error::Pipe), inner);
+    }
+}
@@ -218,6 +218,47 @@ pub fn request_type(direction: Direction, request_type: RequestType, recipient:
     value
 }
 
+/// Splits a `bmRequestType` value into its three parts.
+///
+/// This is the inverse of [request_type()](fn.request_type.html) and can be used to decode
+/// setup packets captured from the bus.
+///
+/// The recipient field is five bits wide but only values 0 to 3 are defined. Reserved recipient
+/// values are returned as `Recipient::Other`.
+///
+/// ## Examples
+///
+/// ```
+/// use rusb::{Direction,RequestType,Recipient};
+///
+/// assert_eq!(
+///     (Direction::In, RequestType::Vendor, Recipient::Interface),
+///     rusb::parse_request_type(0xC1)
+/// );
+/// ```
+pub fn parse_request_type(value: u8) -> (Direction, RequestType, Recipient) {
+    let direction = match value & LIBUSB_ENDPOINT_DIR_MASK {
+        LIBUSB_ENDPOINT_IN => Direction::In,
+        _ => Direction::Out,
+    };
+
+    let request_type = match value & LIBUSB_REQUEST_TYPE_RESERVED {
+        LIBUSB_REQUEST_TYPE_STANDARD => RequestType::Standard,
+        LIBUSB_REQUEST_TYPE_CLASS => RequestType::Class,
+        LIBUSB_REQUEST_TYPE_VENDOR => RequestType::Vendor,
+        _ => RequestType::Reserved,
+    };
+
+    let recipient = match value & 0x1F {
+        LIBUSB_RECIPIENT_DEVICE => Recipient::Device,
+        LIBUSB_RECIPIENT_INTERFACE => Recipient::Interface,
+        LIBUSB_RECIPIENT_ENDPOINT => Recipient::Endpoint,
+        _ => Recipient::Other,
+    };
+
+    (direction, request_type, recipient)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -354,4 +395,41 @@ mod test {
             0x03
         );
     }
+
+    // parse_request_type
+
+    #[test]
+    fn parse_request_type_inverts_request_type() {
+        let directions = [Direction::In, Direction::Out];
+        let request_types = [
+            RequestType::Standard,
+            RequestType::Class,
+            RequestType::Vendor,
+            RequestType::Reserved,
+        ];
+        let recipients = [
+            Recipient::Device,
+            Recipient::Interface,
+            Recipient::Endpoint,
+            Recipient::Other,
+        ];
+
+        for &direction in directions.iter() {
+            for &request_type in request_types.iter() {
+                for &recipient in recipients.iter() {
+                    assert_eq!(
+                        (direction, request_type, recipient),
+                        parse_request_type(super::request_type(direction, request_type, recipient))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse_request_type_maps_reserved_recipients_to_other() {
+        for value in 0x04..=0x1F {
+            assert_eq!(Recipient::Other, parse_request_type(value).2);
+        }
+    }
 }
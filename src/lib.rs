@@ -3,8 +3,10 @@
 pub use libusb1_sys::constants;
 
 pub use crate::{
+    bulk_io::{BulkReader, BulkWriter},
     config_descriptor::{ConfigDescriptor, Interfaces},
     context::{Context, GlobalContext, Hotplug, LogLevel, Registration, UsbContext},
+    control_reader::{ControlReader, OffsetEncoding},
     device::Device,
     device_descriptor::DeviceDescriptor,
     device_handle::DeviceHandle,
@@ -12,8 +14,8 @@ pub use crate::{
     endpoint_descriptor::EndpointDescriptor,
     error::{Error, Result},
     fields::{
-        request_type, Direction, Recipient, RequestType, Speed, SyncType, TransferType, UsageType,
-        Version,
+        parse_request_type, request_type, Direction, Recipient, RequestType, Speed, SyncType,
+        TransferType, UsageType, Version,
     },
     interface_descriptor::{
         EndpointDescriptors, Interface, InterfaceDescriptor, InterfaceDescriptors,
@@ -31,7 +33,9 @@ mod test_helpers;
 mod error;
 mod version;
 
+mod bulk_io;
 mod context;
+mod control_reader;
 mod device;
 mod device_handle;
 mod device_list;